@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Health probe configuration for a backend agent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HealthProbe {
+    pub url: String,
+    pub interval_secs: u64,
+}
+
+/// A pluggable backend that `BackendManager` can spawn and supervise.
+pub trait Backend: Send + Sync {
+    /// Unique name used for logging, process tracking, and Tauri commands.
+    fn name(&self) -> &str;
+
+    /// Python module path passed to `uv run -m <module>`.
+    fn module(&self) -> &str;
+
+    /// Environment variable overrides to apply on top of `.env` when spawning.
+    fn env_overrides(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+
+    /// Optional health probe describing how to tell the backend is ready.
+    fn health_probe(&self) -> Option<HealthProbe> {
+        None
+    }
+
+    /// Build the (not yet spawned) command used to launch this backend.
+    /// Defaults to `uv run --env-file <env_path> -m <module>`; override to
+    /// launch something other than a `uv`-run Python module.
+    fn command(&self, uv_cmd: &str, env_path: &Path, python: Option<&str>) -> Command {
+        let mut command = Command::new(uv_cmd);
+        command.arg("run");
+        if let Some(python) = python {
+            command.arg("--python").arg(python);
+        }
+        command
+            .arg("--env-file")
+            .arg(env_path)
+            .arg("-m")
+            .arg(self.module());
+        command
+    }
+}
+
+/// Default backend implementation: runs a Python module through `uv run`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UvPythonBackend {
+    name: String,
+    module: String,
+    #[serde(default)]
+    env: HashMap<String, String>,
+    #[serde(default)]
+    health_probe: Option<HealthProbe>,
+}
+
+impl Backend for UvPythonBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn module(&self) -> &str {
+        &self.module
+    }
+
+    fn env_overrides(&self) -> HashMap<String, String> {
+        self.env.clone()
+    }
+
+    fn health_probe(&self) -> Option<HealthProbe> {
+        self.health_probe.clone()
+    }
+}
+
+/// On-disk manifest format for `backends.toml` / `agents.toml`.
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    #[serde(default, rename = "backend")]
+    backends: Vec<UvPythonBackend>,
+}
+
+/// Built-in agents used when no manifest is present.
+fn builtin_backends() -> Vec<Box<dyn Backend>> {
+    vec![
+        Box::new(UvPythonBackend {
+            name: "ResearchAgent".to_string(),
+            module: "valuecell.agents.research_agent".to_string(),
+            env: HashMap::new(),
+            health_probe: None,
+        }),
+        Box::new(UvPythonBackend {
+            name: "AutoTradingAgent".to_string(),
+            module: "valuecell.agents.auto_trading_agent".to_string(),
+            env: HashMap::new(),
+            health_probe: None,
+        }),
+        Box::new(UvPythonBackend {
+            name: "NewsAgent".to_string(),
+            module: "valuecell.agents.news_agent".to_string(),
+            env: HashMap::new(),
+            health_probe: None,
+        }),
+    ]
+}
+
+/// Discover the backend registry from `backends.toml`/`agents.toml` next to
+/// `pyproject.toml`, falling back to the built-in agent trio when neither
+/// manifest exists.
+pub fn discover_backends(backend_path: &Path) -> Result<Vec<Box<dyn Backend>>> {
+    for manifest_name in ["backends.toml", "agents.toml"] {
+        let manifest_path = backend_path.join(manifest_name);
+        if !manifest_path.exists() {
+            continue;
+        }
+
+        log::info!("Loading backend registry from: {:?}", manifest_path);
+        let contents = std::fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {:?}", manifest_path))?;
+        let manifest: Manifest = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {:?}", manifest_path))?;
+
+        let backends: Vec<Box<dyn Backend>> = manifest
+            .backends
+            .into_iter()
+            .map(|b| Box::new(b) as Box<dyn Backend>)
+            .collect();
+
+        if backends.is_empty() {
+            log::warn!("{:?} declared no [[backend]] entries, using built-ins", manifest_path);
+            return Ok(builtin_backends());
+        }
+
+        return Ok(backends);
+    }
+
+    log::info!("No backends.toml/agents.toml found, using built-in agent trio");
+    Ok(builtin_backends())
+}
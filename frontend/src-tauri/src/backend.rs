@@ -1,16 +1,41 @@
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashSet;
 use std::fs::{File, create_dir_all};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Manager};
 
+use crate::agent_backend::{self, Backend};
+use crate::env_check::{self, EnvValidationError};
+use crate::log_stream;
+use crate::migrations;
+use crate::python_env::{self, PythonMode};
+use crate::supervisor::{self, DisabledSet, ManagedProcess, ProcessKind, Supervisor};
+
+/// Snapshot of one tracked process, returned by the `list_processes` command.
+#[derive(Debug, Serialize)]
+pub struct ProcessInfo {
+    pub name: String,
+    pub pid: Option<u32>,
+    pub alive: bool,
+    pub restart_count: u32,
+}
+
 /// Backend process manager
 pub struct BackendManager {
-    processes: Mutex<Vec<Child>>,
+    processes: Arc<Mutex<Vec<ManagedProcess>>>,
     backend_path: PathBuf,
     env_path: PathBuf,
     log_dir: PathBuf,
+    data_dir: PathBuf,
+    backends: Arc<Vec<Box<dyn Backend>>>,
+    stopping: Arc<AtomicBool>,
+    /// Process names the user has durably stopped via `stop_agent`.
+    disabled: DisabledSet,
+    app_handle: AppHandle,
 }
 
 impl BackendManager {
@@ -78,27 +103,55 @@ impl BackendManager {
         create_dir_all(&log_dir)
             .context("Failed to create log directory")?;
 
+        // Holds the managed Python interpreter (when PYTHON_MODE=managed) and
+        // its resolved-version cache.
+        let data_dir = app
+            .path()
+            .app_data_dir()
+            .context("Failed to get app data directory")?;
+
+        create_dir_all(&data_dir)
+            .context("Failed to create app data directory")?;
+
         log::info!("Mode: {}", if cfg!(debug_assertions) { "Development" } else { "Production" });
         log::info!("Backend path: {:?}", backend_path);
         log::info!("Env path: {:?}", env_path);
         log::info!("Log directory: {:?}", log_dir);
+        log::info!("Data directory: {:?}", data_dir);
+
+        let backends = agent_backend::discover_backends(&backend_path)?;
+        log::info!(
+            "Registered backends: {:?}",
+            backends.iter().map(|b| b.name()).collect::<Vec<_>>()
+        );
 
         Ok(Self {
-            processes: Mutex::new(Vec::new()),
+            processes: Arc::new(Mutex::new(Vec::new())),
             backend_path,
             env_path,
             log_dir,
+            data_dir,
+            backends: Arc::new(backends),
+            stopping: Arc::new(AtomicBool::new(false)),
+            disabled: Arc::new(Mutex::new(HashSet::new())),
+            app_handle: app.clone(),
         })
     }
 
+    /// Path to the `.env.example` template (Resources root, same level as `backend/`).
+    fn env_template_path(&self) -> Result<PathBuf> {
+        Ok(self
+            .backend_path
+            .parent()
+            .ok_or_else(|| anyhow::anyhow!("Cannot get parent directory"))?
+            .join(".env.example"))
+    }
+
     /// Check if .env file exists, if not, copy from template
     pub fn ensure_env_file(&self) -> Result<()> {
         if !self.env_path.exists() {
-            // .env.example is in Resources root (same level as backend/)
-            let template_path = self.backend_path.parent()
-                .ok_or_else(|| anyhow::anyhow!("Cannot get parent directory"))?
-                .join(".env.example");
-            
+            let template_path = self.env_template_path()?;
+
             if template_path.exists() {
                 std::fs::copy(&template_path, &self.env_path)
                     .context("Failed to copy .env.example template")?;
@@ -117,14 +170,22 @@ impl BackendManager {
         Ok(())
     }
 
-    /// Find Python interpreter (system Python or bundled)
-    fn find_python(&self) -> Result<String> {
-        // For now, use system Python
-        // In the future, we could bundle Python with the app
-        
+    /// Check every key declared in `.env.example` is present and non-empty
+    /// in `.env`.
+    pub fn validate_env(&self) -> Result<()> {
+        let missing = env_check::validate(&self.env_template_path()?, &self.env_path)?;
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(EnvValidationError { missing }.into())
+        }
+    }
+
+    /// Probe the system for a Python interpreter on PATH.
+    fn find_system_python(&self) -> Result<String> {
         // Try common Python commands
         let python_commands = vec!["python3", "python"];
-        
+
         for cmd in python_commands {
             if Command::new(cmd)
                 .arg("--version")
@@ -136,10 +197,33 @@ impl BackendManager {
                 return Ok(cmd.to_string());
             }
         }
-        
+
         Err(anyhow::anyhow!("Python not found. Please install Python 3.12+"))
     }
 
+    /// Resolve the Python interpreter to pass to `uv run --python`.
+    ///
+    /// In `PYTHON_MODE=managed`, fetches a pinned standalone CPython via `uv`
+    /// into the app data dir and returns its path. Falls back to the system
+    /// Python probe otherwise.
+    fn resolve_python(&self, uv_cmd: &str) -> Result<Option<String>> {
+        if PythonMode::from_env() == PythonMode::Managed {
+            match python_env::provision_managed(&self.data_dir, &self.backend_path, uv_cmd) {
+                Ok(path) => return Ok(Some(path)),
+                Err(e) => {
+                    log::warn!(
+                        "Managed Python provisioning failed ({}), falling back to system Python",
+                        e
+                    );
+                }
+            }
+        }
+
+        let system_python = self.find_system_python()?;
+        log::info!("Using system Python: {}", system_python);
+        Ok(None)
+    }
+
     /// Find or install uv
     fn find_uv(&self) -> Result<String> {
         // Common uv installation paths (with ~ for home directory)
@@ -171,116 +255,22 @@ impl BackendManager {
         Err(anyhow::anyhow!("uv not found. Please install uv: https://docs.astral.sh/uv/getting-started/installation/\nSearched paths: {:?}", uv_paths))
     }
 
-    /// Start a single agent
-    fn start_agent(&self, agent_name: &str, uv_cmd: &str) -> Result<Child> {
-        let module_name = match agent_name {
-            "ResearchAgent" => "valuecell.agents.research_agent",
-            "AutoTradingAgent" => "valuecell.agents.auto_trading_agent",
-            "NewsAgent" => "valuecell.agents.news_agent",
-            _ => return Err(anyhow::anyhow!("Unknown agent: {}", agent_name)),
-        };
-
-        // Verify backend path exists
-        if !self.backend_path.exists() {
-            return Err(anyhow::anyhow!(
-                "Backend path does not exist: {:?}", 
-                self.backend_path
-            ));
-        }
+    /// Install dependencies using uv sync, skipping it when `.venv` exists
+    /// and already matches `uv.lock`/`pyproject.toml`.
+    fn install_dependencies(&self, uv_cmd: &str) -> Result<()> {
+        log::info!("Checking Python dependencies...");
 
-        // Verify env file exists
-        if !self.env_path.exists() {
-            return Err(anyhow::anyhow!(
-                "Env file does not exist: {:?}", 
-                self.env_path
-            ));
-        }
+        let fingerprint = self.lockfile_fingerprint()?;
+        let fingerprint_path = self.data_dir.join("dependency_fingerprint.txt");
+        let venv_path = self.backend_path.join(".venv");
 
-        // Create log files for stdout and stderr
-        let log_file = self.log_dir.join(format!("{}.log", agent_name));
-        let stdout_file = File::create(&log_file)
-            .context(format!("Failed to create log file for {}", agent_name))?;
-        let stderr_file = stdout_file.try_clone()
-            .context("Failed to clone log file handle")?;
-
-        log::info!("Starting {} with log file: {:?}", agent_name, log_file);
-        log::info!("Command: {} run --env-file {:?} -m {}", uv_cmd, self.env_path, module_name);
-        log::info!("Working directory: {:?}", self.backend_path);
-
-        // First, test if the command works by doing a dry run
-        log::info!("Testing command availability...");
-        let test_result = Command::new(uv_cmd)
-            .arg("--version")
-            .output();
-        
-        match test_result {
-            Ok(output) => {
-                log::info!("UV version check: {:?}", String::from_utf8_lossy(&output.stdout));
-            }
-            Err(e) => {
-                log::error!("UV command test failed: {}", e);
-            }
+        if venv_path.exists()
+            && std::fs::read_to_string(&fingerprint_path).ok().as_deref() == Some(fingerprint.as_str())
+        {
+            log::info!("✓ Environment up to date (skipped sync)");
+            return Ok(());
         }
 
-        // Run agent in backend directory (python/)
-        let mut command = Command::new(uv_cmd);
-        command
-            .arg("run")
-            .arg("--env-file")
-            .arg(&self.env_path)
-            .arg("-m")
-            .arg(module_name)
-            .current_dir(&self.backend_path)
-            .stdout(Stdio::from(stdout_file))
-            .stderr(Stdio::from(stderr_file));
-
-        log::info!("Spawning process...");
-        let child = command.spawn()
-            .context(format!("Failed to spawn {}", agent_name))?;
-
-        let pid = child.id();
-        log::info!("✓ {} spawned with PID: {}", agent_name, pid);
-        
-        // Wait a moment to see if the process exits immediately
-        std::thread::sleep(std::time::Duration::from_millis(500));
-        
-        Ok(child)
-    }
-
-    /// Start backend server
-    fn start_backend_server(&self, uv_cmd: &str) -> Result<Child> {
-        // Create log files for stdout and stderr
-        let log_file = self.log_dir.join("backend_server.log");
-        let stdout_file = File::create(&log_file)
-            .context("Failed to create log file for backend server")?;
-        let stderr_file = stdout_file.try_clone()
-            .context("Failed to clone log file handle")?;
-
-        log::info!("Starting backend server with log file: {:?}", log_file);
-        log::info!("Command: {} run --env-file {:?} -m valuecell.server.main", uv_cmd, self.env_path);
-        log::info!("Working directory: {:?}", self.backend_path);
-
-        // Run backend server in backend directory (python/)
-        let child = Command::new(uv_cmd)
-            .arg("run")
-            .arg("--env-file")
-            .arg(&self.env_path)
-            .arg("-m")
-            .arg("valuecell.server.main")
-            .current_dir(&self.backend_path)
-            .stdout(Stdio::from(stdout_file))
-            .stderr(Stdio::from(stderr_file))
-            .spawn()
-            .context("Failed to start backend server")?;
-
-        log::info!("✓ Backend server started with PID: {}", child.id());
-        Ok(child)
-    }
-
-    /// Install dependencies using uv sync
-    fn install_dependencies(&self, uv_cmd: &str) -> Result<()> {
-        log::info!("Checking Python dependencies...");
-        
         // Run uv sync to install dependencies
         let output = Command::new(uv_cmd)
             .arg("sync")
@@ -295,132 +285,316 @@ impl BackendManager {
             return Err(anyhow::anyhow!("Failed to sync dependencies: {}", stderr));
         }
 
-        log::info!("✓ Dependencies installed/verified");
+        std::fs::write(&fingerprint_path, &fingerprint)
+            .context("Failed to cache dependency fingerprint")?;
+
+        log::info!("✓ Dependencies installed (environment was out of date)");
         Ok(())
     }
 
-    /// Initialize database
-    fn init_database(&self, uv_cmd: &str) -> Result<()> {
-        log::info!("Initializing database...");
-        
-        let init_db_script = self.backend_path.join("valuecell/server/db/init_db.py");
-        
-        // Check if init_db.py exists
-        if !init_db_script.exists() {
-            log::warn!("Database init script not found at: {:?}", init_db_script);
-            log::warn!("Skipping database initialization");
-            return Ok(());
+    /// Hash `uv.lock` and `pyproject.toml` together into a cache key for
+    /// `install_dependencies`'s skip check.
+    fn lockfile_fingerprint(&self) -> Result<String> {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for file_name in ["uv.lock", "pyproject.toml"] {
+            let path = self.backend_path.join(file_name);
+            let contents = std::fs::read(&path)
+                .with_context(|| format!("Failed to read {:?}", path))?;
+            contents.hash(&mut hasher);
         }
 
-        // Run database initialization
-        let output = Command::new(uv_cmd)
-            .arg("run")
-            .arg("--env-file")
-            .arg(&self.env_path)
-            .arg(&init_db_script)
-            .current_dir(&self.backend_path)
-            .output()
-            .context("Failed to run database initialization")?;
+        Ok(format!("{:x}", hasher.finish()))
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            log::warn!("Database initialization output: {}", stdout);
-            log::warn!("Database initialization stderr: {}", stderr);
-            // Don't fail if database already initialized
-            log::warn!("Database initialization had warnings, but continuing...");
+    /// Directory holding ordered, timestamp-prefixed migration scripts.
+    fn migrations_dir(&self) -> PathBuf {
+        self.backend_path.join("valuecell/server/db/migrations")
+    }
+
+    /// File tracking which migration versions have already been applied.
+    fn migrations_state_path(&self) -> PathBuf {
+        self.data_dir.join("migrations_applied.json")
+    }
+
+    /// Run every pending database migration.
+    fn init_database(&self, uv_cmd: &str, python: Option<&str>) -> Result<()> {
+        log::info!("Running database migrations...");
+
+        let applied = migrations::migrate(
+            &self.migrations_dir(),
+            &self.migrations_state_path(),
+            &self.backend_path,
+            &self.env_path,
+            uv_cmd,
+            python,
+        )?;
+
+        if applied.is_empty() {
+            log::info!("✓ Database already up to date");
         } else {
-            log::info!("✓ Database initialized");
+            log::info!("✓ Applied {} migration(s): {:?}", applied.len(), applied);
         }
 
         Ok(())
     }
 
+    /// Report which migrations are applied vs. still pending.
+    pub fn migrate_status(&self) -> Result<migrations::MigrationStatus> {
+        migrations::status(&self.migrations_dir(), &self.migrations_state_path())
+    }
+
+    /// Revert the most recently applied migration.
+    pub fn revert_last_migration(&self) -> Result<Option<String>> {
+        let uv_cmd = self.find_uv()?;
+        let python = self.resolve_python(&uv_cmd)?;
+        migrations::revert(
+            &self.migrations_dir(),
+            &self.migrations_state_path(),
+            &self.backend_path,
+            &self.env_path,
+            &uv_cmd,
+            python.as_deref(),
+        )
+    }
+
     /// Start all backend processes (agents + server)
     pub fn start_all(&self) -> Result<()> {
         log::info!("Starting ValueCell backend...");
         log::info!("📁 Backend logs will be saved to: {:?}", self.log_dir);
 
-        // Check Python
-        self.find_python()?;
-
         // Check uv
         let uv_cmd = self.find_uv()?;
         log::info!("Found uv: {}", uv_cmd);
 
+        // Resolve the Python interpreter (managed or system, see PYTHON_MODE)
+        let python = self.resolve_python(&uv_cmd)?;
+
         // Ensure .env exists
         self.ensure_env_file()?;
 
+        // Abort before spawning anything doomed to crash over a missing key
+        self.validate_env()?;
+
         // Install dependencies if not already installed
         self.install_dependencies(&uv_cmd)?;
 
         // Initialize database
-        self.init_database(&uv_cmd)?;
-
-        let mut processes = self.processes.lock().unwrap();
+        self.init_database(&uv_cmd, python.as_deref())?;
+
+        {
+            let mut processes = self.processes.lock().unwrap();
+
+            // Start agents from the registered backend list
+            for backend in self.backends.iter() {
+                match spawn_agent(
+                    &self.app_handle,
+                    backend.as_ref(),
+                    &self.backend_path,
+                    &self.env_path,
+                    &self.log_dir,
+                    &uv_cmd,
+                    python.as_deref(),
+                ) {
+                    Ok(child) => {
+                        log::info!("Process {} added to process list", child.id());
+                        processes.push(ManagedProcess::new(
+                            child,
+                            ProcessKind::Agent(backend.name().to_string()),
+                        ));
+                    }
+                    Err(e) => log::error!("Failed to start {}: {}", backend.name(), e),
+                }
+            }
 
-        // Start agents
-        let agents = vec!["ResearchAgent", "AutoTradingAgent", "NewsAgent"];
-        for agent_name in agents {
-            match self.start_agent(agent_name, &uv_cmd) {
+            // Start backend server
+            match spawn_backend_server(
+                &self.app_handle,
+                &self.backend_path,
+                &self.env_path,
+                &self.log_dir,
+                &uv_cmd,
+                python.as_deref(),
+            ) {
                 Ok(child) => {
                     log::info!("Process {} added to process list", child.id());
-                    processes.push(child);
+                    processes.push(ManagedProcess::new(child, ProcessKind::Server));
+                }
+                Err(e) => log::error!("Failed to start backend server: {}", e),
+            }
+
+            log::info!("✓ All backend processes started (total: {})", processes.len());
+
+            // Check if processes are still alive after a short delay
+            std::thread::sleep(std::time::Duration::from_secs(1));
+
+            let mut alive_count = 0;
+            for process in processes.iter_mut() {
+                match process.child.try_wait() {
+                    Ok(None) => {
+                        // Process is still running
+                        alive_count += 1;
+                    }
+                    Ok(Some(status)) => {
+                        log::warn!("{} exited with status: {:?}", process.kind, status);
+                    }
+                    Err(e) => {
+                        log::error!("Error checking process status: {}", e);
+                    }
                 }
-                Err(e) => log::error!("Failed to start {}: {}", agent_name, e),
             }
-        }
 
-        // Start backend server
-        match self.start_backend_server(&uv_cmd) {
-            Ok(child) => {
-                log::info!("Process {} added to process list", child.id());
-                processes.push(child);
+            log::info!("Processes still alive: {}/{}", alive_count, processes.len());
+
+            if alive_count == 0 && !processes.is_empty() {
+                log::error!("⚠️  All processes exited immediately! Check log files for errors.");
             }
-            Err(e) => log::error!("Failed to start backend server: {}", e),
         }
 
-        log::info!("✓ All backend processes started (total: {})", processes.len());
-        
-        // Check if processes are still alive after a short delay
-        std::thread::sleep(std::time::Duration::from_secs(1));
-        
-        let mut alive_count = 0;
-        for process in processes.iter_mut() {
-            match process.try_wait() {
-                Ok(None) => {
-                    // Process is still running
-                    alive_count += 1;
-                }
-                Ok(Some(status)) => {
-                    log::warn!("Process {} exited with status: {:?}", process.id(), status);
-                }
-                Err(e) => {
-                    log::error!("Error checking process status: {}", e);
-                }
+        // Watch the fleet and restart crashed processes with backoff.
+        let supervisor = Arc::new(Supervisor::new(
+            Arc::clone(&self.processes),
+            Arc::clone(&self.backends),
+            self.backend_path.clone(),
+            self.env_path.clone(),
+            self.log_dir.clone(),
+            uv_cmd,
+            python,
+            Arc::clone(&self.stopping),
+            Arc::clone(&self.disabled),
+            self.app_handle.clone(),
+        ));
+        supervisor::spawn_thread(supervisor);
+
+        Ok(())
+    }
+
+    /// Snapshot every tracked process for the `list_processes` command.
+    pub fn list_processes(&self) -> Vec<ProcessInfo> {
+        let mut processes = self.processes.lock().unwrap();
+        processes
+            .iter_mut()
+            .map(|proc| ProcessInfo {
+                name: proc.kind.to_string(),
+                pid: Some(proc.child.id()),
+                alive: matches!(proc.child.try_wait(), Ok(None)),
+                restart_count: proc.restart_count,
+            })
+            .collect()
+    }
+
+    /// Kill and immediately respawn the named process (agent name, or
+    /// `"backend server"`), resetting its restart backoff. Works even if
+    /// `name` isn't currently tracked (crashed and mid-backoff, or already
+    /// gave up after `MAX_RESTARTS`), by resolving `name` against the known
+    /// backends/server kind rather than requiring a live `processes` entry.
+    pub fn restart_process(&self, name: &str) -> Result<()> {
+        // The user explicitly wants this process running again, so it's no
+        // longer durably stopped.
+        self.disabled.lock().unwrap().remove(name);
+
+        {
+            let mut processes = self.processes.lock().unwrap();
+            if let Some(pos) = processes.iter().position(|p| p.kind.to_string() == name) {
+                let mut proc = processes.remove(pos);
+                let _ = proc.child.kill();
             }
         }
-        
-        log::info!("Processes still alive: {}/{}", alive_count, processes.len());
-        
-        if alive_count == 0 && processes.len() > 0 {
-            log::error!("⚠️  All processes exited immediately! Check log files for errors.");
+
+        let kind = if name == ProcessKind::Server.to_string() {
+            ProcessKind::Server
+        } else if self.backends.iter().any(|b| b.name() == name) {
+            ProcessKind::Agent(name.to_string())
+        } else {
+            return Err(anyhow::anyhow!("No such process: {}", name));
+        };
+
+        let uv_cmd = self.find_uv()?;
+        let python = self.resolve_python(&uv_cmd)?;
+
+        let child = match &kind {
+            ProcessKind::Agent(agent_name) => {
+                let backend = self
+                    .backends
+                    .iter()
+                    .find(|b| b.name() == agent_name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown backend: {}", agent_name))?;
+                spawn_agent(
+                    &self.app_handle,
+                    backend.as_ref(),
+                    &self.backend_path,
+                    &self.env_path,
+                    &self.log_dir,
+                    &uv_cmd,
+                    python.as_deref(),
+                )?
+            }
+            ProcessKind::Server => spawn_backend_server(
+                &self.app_handle,
+                &self.backend_path,
+                &self.env_path,
+                &self.log_dir,
+                &uv_cmd,
+                python.as_deref(),
+            )?,
+        };
+
+        log::info!("✓ {} restarted with PID: {} (manual restart)", kind, child.id());
+        self.processes
+            .lock()
+            .unwrap()
+            .push(ManagedProcess::new(child, kind));
+        Ok(())
+    }
+
+    /// Kill the named process and stop tracking it (no auto-restart). Marks
+    /// `name` disabled even if it isn't currently tracked (e.g. mid-backoff
+    /// after a crash), so the supervisor won't respawn it later.
+    pub fn stop_process(&self, name: &str) -> Result<()> {
+        self.disabled.lock().unwrap().insert(name.to_string());
+
+        let mut processes = self.processes.lock().unwrap();
+        if let Some(pos) = processes.iter().position(|p| p.kind.to_string() == name) {
+            let mut proc = processes.remove(pos);
+            proc.child
+                .kill()
+                .with_context(|| format!("Failed to stop {}", name))?;
         }
-        
         Ok(())
     }
 
+    /// Return the last `lines` lines logged by the named process.
+    pub fn tail_log(&self, name: &str, lines: usize) -> Result<Vec<String>> {
+        let log_file = if name == ProcessKind::Server.to_string() {
+            self.log_dir.join("backend_server.log")
+        } else {
+            self.log_dir.join(format!("{}.log", name))
+        };
+
+        let contents = std::fs::read_to_string(&log_file)
+            .with_context(|| format!("Failed to read {:?}", log_file))?;
+
+        let mut tail: Vec<String> = contents.lines().rev().take(lines).map(String::from).collect();
+        tail.reverse();
+        Ok(tail)
+    }
+
     /// Stop all backend processes
     pub fn stop_all(&self) {
         log::info!("Stopping all backend processes...");
-        
+
+        // Tell the supervisor to stop restarting before we start killing
+        // processes out from under it.
+        self.stopping.store(true, std::sync::atomic::Ordering::SeqCst);
+
         let mut processes = self.processes.lock().unwrap();
         for mut process in processes.drain(..) {
-            if let Err(e) = process.kill() {
-                log::error!("Failed to stop process {}: {}", process.id(), e);
+            if let Err(e) = process.child.kill() {
+                log::error!("Failed to stop process {}: {}", process.kind, e);
             }
         }
-        
+
         log::info!("✓ All backend processes stopped");
     }
 }
@@ -431,3 +605,126 @@ impl Drop for BackendManager {
     }
 }
 
+/// Spawn a single agent process for `backend` using its launch command
+/// (`uv run` unless the backend overrides `Backend::command`).
+pub(crate) fn spawn_agent(
+    app_handle: &AppHandle,
+    backend: &dyn Backend,
+    backend_path: &Path,
+    env_path: &Path,
+    log_dir: &Path,
+    uv_cmd: &str,
+    python: Option<&str>,
+) -> Result<Child> {
+    let agent_name = backend.name();
+
+    // Verify backend path exists
+    if !backend_path.exists() {
+        return Err(anyhow::anyhow!(
+            "Backend path does not exist: {:?}",
+            backend_path
+        ));
+    }
+
+    // Verify env file exists
+    if !env_path.exists() {
+        return Err(anyhow::anyhow!("Env file does not exist: {:?}", env_path));
+    }
+
+    // Create the log file stdout/stderr readers persist into, alongside the
+    // live `backend-log` events they emit.
+    let log_file = log_dir.join(format!("{}.log", agent_name));
+    let stdout_log = File::create(&log_file)
+        .context(format!("Failed to create log file for {}", agent_name))?;
+    let stderr_log = stdout_log
+        .try_clone()
+        .context("Failed to clone log file handle")?;
+
+    // Defer entirely to the backend's own launch command, so a custom
+    // `Backend` impl can run something other than `uv run -m <module>`.
+    let mut command = backend.command(uv_cmd, env_path, python);
+    command
+        .current_dir(backend_path)
+        .envs(backend.env_overrides())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    log::info!("Starting {} with log file: {:?}", agent_name, log_file);
+    log::info!("Command: {:?}", command);
+    log::info!("Working directory: {:?}", backend_path);
+
+    log::info!("Spawning process...");
+    let mut child = command
+        .spawn()
+        .context(format!("Failed to spawn {}", agent_name))?;
+
+    log_stream::spawn_reader(
+        app_handle.clone(),
+        agent_name.to_string(),
+        "stdout",
+        child.stdout.take(),
+        stdout_log,
+    );
+    log_stream::spawn_reader(
+        app_handle.clone(),
+        agent_name.to_string(),
+        "stderr",
+        child.stderr.take(),
+        stderr_log,
+    );
+
+    log::info!("✓ {} spawned with PID: {}", agent_name, child.id());
+    Ok(child)
+}
+
+/// Spawn the backend server process through `uv run`.
+pub(crate) fn spawn_backend_server(
+    app_handle: &AppHandle,
+    backend_path: &Path,
+    env_path: &Path,
+    log_dir: &Path,
+    uv_cmd: &str,
+    python: Option<&str>,
+) -> Result<Child> {
+    let name = ProcessKind::Server.to_string();
+
+    // Create the log file stdout/stderr readers persist into, alongside the
+    // live `backend-log` events they emit.
+    let log_file = log_dir.join("backend_server.log");
+    let stdout_log =
+        File::create(&log_file).context("Failed to create log file for backend server")?;
+    let stderr_log = stdout_log
+        .try_clone()
+        .context("Failed to clone log file handle")?;
+
+    log::info!("Starting backend server with log file: {:?}", log_file);
+    log::info!(
+        "Command: {} run --env-file {:?} -m valuecell.server.main",
+        uv_cmd, env_path
+    );
+    log::info!("Working directory: {:?}", backend_path);
+
+    // Run backend server in backend directory (python/)
+    let mut command = Command::new(uv_cmd);
+    command.arg("run");
+    if let Some(python) = python {
+        command.arg("--python").arg(python);
+    }
+    let mut child = command
+        .arg("--env-file")
+        .arg(env_path)
+        .arg("-m")
+        .arg("valuecell.server.main")
+        .current_dir(backend_path)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to start backend server")?;
+
+    log_stream::spawn_reader(app_handle.clone(), name.clone(), "stdout", child.stdout.take(), stdout_log);
+    log_stream::spawn_reader(app_handle.clone(), name, "stderr", child.stderr.take(), stderr_log);
+
+    log::info!("✓ Backend server started with PID: {}", child.id());
+    Ok(child)
+}
+
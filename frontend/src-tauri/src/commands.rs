@@ -0,0 +1,54 @@
+use tauri::State;
+
+use crate::backend::{BackendManager, ProcessInfo};
+use crate::env_check::EnvValidationError;
+use crate::migrations::MigrationStatus;
+
+/// List every tracked backend process (agents + server).
+#[tauri::command]
+pub fn list_processes(manager: State<BackendManager>) -> Vec<ProcessInfo> {
+    manager.list_processes()
+}
+
+/// Kill and respawn a backend process by name (agent name, or `"backend server"`).
+#[tauri::command]
+pub fn restart_agent(manager: State<BackendManager>, name: String) -> Result<(), String> {
+    manager.restart_process(&name).map_err(|e| e.to_string())
+}
+
+/// Kill a backend process by name and stop tracking it.
+#[tauri::command]
+pub fn stop_agent(manager: State<BackendManager>, name: String) -> Result<(), String> {
+    manager.stop_process(&name).map_err(|e| e.to_string())
+}
+
+/// Return the last `lines` lines logged by a backend process.
+#[tauri::command]
+pub fn tail_log(manager: State<BackendManager>, name: String, lines: usize) -> Result<Vec<String>, String> {
+    manager.tail_log(&name, lines).map_err(|e| e.to_string())
+}
+
+/// Check `.env` against `.env.example`, returning the list of required keys
+/// that are missing or empty. Any other failure (e.g. an unreadable file) is
+/// returned as a one-entry list holding its error message.
+#[tauri::command]
+pub fn check_env(manager: State<BackendManager>) -> Result<(), EnvValidationError> {
+    manager.validate_env().map_err(|e| match e.downcast::<EnvValidationError>() {
+        Ok(validation_err) => validation_err,
+        Err(other) => EnvValidationError {
+            missing: vec![other.to_string()],
+        },
+    })
+}
+
+/// Report which database migrations are applied vs. still pending.
+#[tauri::command]
+pub fn migrate_status(manager: State<BackendManager>) -> Result<MigrationStatus, String> {
+    manager.migrate_status().map_err(|e| e.to_string())
+}
+
+/// Revert the most recently applied database migration.
+#[tauri::command]
+pub fn revert_migration(manager: State<BackendManager>) -> Result<Option<String>, String> {
+    manager.revert_last_migration().map_err(|e| e.to_string())
+}
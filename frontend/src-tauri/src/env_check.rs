@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+/// Built from the keys [`validate`] reports when `.env` is missing keys the
+/// template declares, or declares them with an empty value. Carries the key names.
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvValidationError {
+    pub missing: Vec<String>,
+}
+
+impl std::fmt::Display for EnvValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Missing or empty required .env key(s): {}",
+            self.missing.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for EnvValidationError {}
+
+/// Parse the keys in `template_path` (e.g. `.env.example`) and check that
+/// each one is present and non-empty in `env_path` (the real `.env`).
+/// Returns the sorted list of keys that are missing or empty.
+///
+/// Returns an empty list if `template_path` doesn't exist.
+pub fn validate(template_path: &Path, env_path: &Path) -> Result<Vec<String>> {
+    if !template_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let required = parse_env_file(template_path)?;
+    let actual = if env_path.exists() {
+        parse_env_file(env_path)?
+    } else {
+        HashMap::new()
+    };
+
+    let mut missing: Vec<String> = required
+        .keys()
+        .filter(|key| actual.get(*key).map(|v| v.is_empty()).unwrap_or(true))
+        .cloned()
+        .collect();
+    missing.sort();
+
+    Ok(missing)
+}
+
+/// Parse a dotenv-style file into a key/value map, handling the common
+/// conventions: blank lines, `#` comments, an optional leading `export `,
+/// and single/double-quoted values.
+fn parse_env_file(path: &Path) -> Result<HashMap<String, String>> {
+    let contents =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+
+    let mut values = HashMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let key = key.trim().to_string();
+        let mut value = value.trim();
+        if (value.starts_with('"') && value.ends_with('"') && value.len() >= 2)
+            || (value.starts_with('\'') && value.ends_with('\'') && value.len() >= 2)
+        {
+            value = &value[1..value.len() - 1];
+        }
+
+        values.insert(key, value.to_string());
+    }
+
+    Ok(values)
+}
@@ -1,4 +1,11 @@
+mod agent_backend;
 mod backend;
+mod commands;
+mod env_check;
+mod log_stream;
+mod migrations;
+mod python_env;
+mod supervisor;
 
 use backend::BackendManager;
 use tauri::Manager;
@@ -52,7 +59,16 @@ pub fn run() {
                 }
             }
         })
-        .invoke_handler(tauri::generate_handler![greet])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            commands::list_processes,
+            commands::restart_agent,
+            commands::stop_agent,
+            commands::tail_log,
+            commands::check_env,
+            commands::migrate_status,
+            commands::revert_migration,
+        ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|app_handle, event| {
@@ -0,0 +1,66 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::thread;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+/// One line emitted on the `backend-log` event as a backend process writes it.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendLogLine {
+    pub name: String,
+    pub stream: String,
+    pub line: String,
+}
+
+/// Stream a child process pipe line-by-line, persisting each line to
+/// `log_writer` and emitting it as a `backend-log` event.
+///
+/// No-op if `stream` is `None` (the pipe wasn't requested).
+pub fn spawn_reader<R: Read + Send + 'static>(
+    app_handle: AppHandle,
+    name: String,
+    stream_label: &'static str,
+    stream: Option<R>,
+    mut log_writer: File,
+) {
+    let Some(stream) = stream else { return };
+
+    thread::spawn(move || {
+        // Read raw bytes rather than `BufRead::lines()`: a child process
+        // writing a non-UTF-8 byte sequence (a binary-ish warning, part of
+        // a traceback) would otherwise error the whole stream dead, taking
+        // both the live console and on-disk log with it.
+        let mut reader = BufReader::new(stream);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {
+                    while matches!(buf.last(), Some(b'\n') | Some(b'\r')) {
+                        buf.pop();
+                    }
+                    let line = String::from_utf8_lossy(&buf).into_owned();
+
+                    if let Err(e) = writeln!(log_writer, "{}", line) {
+                        log::error!("Failed to write {} log for {}: {}", stream_label, name, e);
+                    }
+
+                    let _ = app_handle.emit(
+                        "backend-log",
+                        BackendLogLine {
+                            name: name.clone(),
+                            stream: stream_label.to_string(),
+                            line,
+                        },
+                    );
+                }
+                Err(e) => {
+                    log::error!("Failed to read {} for {}: {}", stream_label, name, e);
+                    break;
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,191 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A single discovered migration, named after Diesel CLI's convention:
+/// `<timestamp>_<description>.py` (e.g. `20260101120000_create_users.py`).
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub version: String,
+    pub path: PathBuf,
+}
+
+/// Pending vs. already-applied migration versions.
+#[derive(Debug, Serialize)]
+pub struct MigrationStatus {
+    pub applied: Vec<String>,
+    pub pending: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AppliedVersions {
+    versions: Vec<String>,
+}
+
+/// Discover migration scripts under `migrations_dir`, ordered by their
+/// timestamp prefix. Returns an empty list if the directory doesn't exist.
+fn discover(migrations_dir: &Path) -> Result<Vec<Migration>> {
+    if !migrations_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut migrations = Vec::new();
+    for entry in std::fs::read_dir(migrations_dir)
+        .with_context(|| format!("Failed to read {:?}", migrations_dir))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("py") {
+            continue;
+        }
+
+        let file_stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Invalid migration filename: {:?}", path))?;
+
+        // Down migrations (`<version>_down.py`) are located on demand by
+        // `revert`, not treated as forward migrations themselves.
+        if file_stem.ends_with("_down") {
+            continue;
+        }
+
+        let version = file_stem
+            .split('_')
+            .next()
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Migration {:?} has no timestamp prefix", path))?
+            .to_string();
+
+        migrations.push(Migration { version, path });
+    }
+
+    migrations.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(migrations)
+}
+
+fn load_applied(state_path: &Path) -> Result<AppliedVersions> {
+    match std::fs::read_to_string(state_path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {:?}", state_path)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(AppliedVersions::default()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", state_path)),
+    }
+}
+
+fn save_applied(state_path: &Path, applied: &AppliedVersions) -> Result<()> {
+    let contents = serde_json::to_string_pretty(applied).context("Failed to serialize migration state")?;
+    std::fs::write(state_path, contents).with_context(|| format!("Failed to write {:?}", state_path))
+}
+
+fn run_script(
+    script: &Path,
+    backend_path: &Path,
+    env_path: &Path,
+    uv_cmd: &str,
+    python: Option<&str>,
+) -> Result<()> {
+    let mut command = Command::new(uv_cmd);
+    command.arg("run");
+    if let Some(python) = python {
+        command.arg("--python").arg(python);
+    }
+    let output = command
+        .arg("--env-file")
+        .arg(env_path)
+        .arg(script)
+        .current_dir(backend_path)
+        .output()
+        .with_context(|| format!("Failed to run {:?}", script))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!("{:?} failed: {}", script, stderr));
+    }
+
+    Ok(())
+}
+
+/// Run every migration under `migrations_dir` that isn't yet recorded in
+/// `state_path`, in order, recording each as it succeeds. Stops and
+/// propagates the error at the first migration that fails.
+pub fn migrate(
+    migrations_dir: &Path,
+    state_path: &Path,
+    backend_path: &Path,
+    env_path: &Path,
+    uv_cmd: &str,
+    python: Option<&str>,
+) -> Result<Vec<String>> {
+    let migrations = discover(migrations_dir)?;
+    let mut applied = load_applied(state_path)?;
+    let mut newly_applied = Vec::new();
+
+    for migration in migrations {
+        if applied.versions.contains(&migration.version) {
+            continue;
+        }
+
+        log::info!("Applying migration {}: {:?}", migration.version, migration.path);
+        run_script(&migration.path, backend_path, env_path, uv_cmd, python)
+            .with_context(|| format!("Migration {} failed", migration.version))?;
+
+        applied.versions.push(migration.version.clone());
+        save_applied(state_path, &applied)?;
+        newly_applied.push(migration.version);
+    }
+
+    Ok(newly_applied)
+}
+
+/// Report applied vs. pending migrations without running anything.
+pub fn status(migrations_dir: &Path, state_path: &Path) -> Result<MigrationStatus> {
+    let migrations = discover(migrations_dir)?;
+    let applied = load_applied(state_path)?;
+
+    let pending = migrations
+        .iter()
+        .filter(|m| !applied.versions.contains(&m.version))
+        .map(|m| m.version.clone())
+        .collect();
+
+    Ok(MigrationStatus {
+        applied: applied.versions,
+        pending,
+    })
+}
+
+/// Revert the most recently applied migration by running its
+/// `<version>_down.py` counterpart.
+pub fn revert(
+    migrations_dir: &Path,
+    state_path: &Path,
+    backend_path: &Path,
+    env_path: &Path,
+    uv_cmd: &str,
+    python: Option<&str>,
+) -> Result<Option<String>> {
+    let mut applied = load_applied(state_path)?;
+    let Some(version) = applied.versions.last().cloned() else {
+        return Ok(None);
+    };
+
+    let down_script = migrations_dir.join(format!("{}_down.py", version));
+    if !down_script.exists() {
+        return Err(anyhow::anyhow!(
+            "No down migration found for {} (expected {:?})",
+            version,
+            down_script
+        ));
+    }
+
+    log::info!("Reverting migration {}: {:?}", version, down_script);
+    run_script(&down_script, backend_path, env_path, uv_cmd, python)
+        .with_context(|| format!("Revert of {} failed", version))?;
+
+    applied.versions.pop();
+    save_applied(state_path, &applied)?;
+
+    Ok(Some(version))
+}
@@ -0,0 +1,139 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// How `BackendManager` should obtain the Python interpreter used by `uv run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PythonMode {
+    /// Fetch a pinned, `uv`-managed standalone CPython into the app data dir.
+    Managed,
+    /// Probe the system for `python3`/`python` on PATH.
+    System,
+}
+
+impl PythonMode {
+    /// Read the desired mode from the `PYTHON_MODE` environment variable,
+    /// defaulting to `System`.
+    pub fn from_env() -> Self {
+        match std::env::var("PYTHON_MODE").as_deref() {
+            Ok("managed") => PythonMode::Managed,
+            _ => PythonMode::System,
+        }
+    }
+}
+
+/// Cached record of a previously-provisioned managed interpreter.
+#[derive(Debug, Serialize, Deserialize)]
+struct ResolvedPython {
+    version: String,
+    path: String,
+}
+
+fn cache_path(install_dir: &Path) -> PathBuf {
+    install_dir.join("resolved.toml")
+}
+
+fn load_cached(install_dir: &Path, version: &str) -> Option<String> {
+    let contents = std::fs::read_to_string(cache_path(install_dir)).ok()?;
+    let resolved: ResolvedPython = toml::from_str(&contents).ok()?;
+    if resolved.version == version && Path::new(&resolved.path).exists() {
+        Some(resolved.path)
+    } else {
+        None
+    }
+}
+
+fn store_cache(install_dir: &Path, version: &str, path: &str) -> Result<()> {
+    let resolved = ResolvedPython {
+        version: version.to_string(),
+        path: path.to_string(),
+    };
+    std::fs::write(cache_path(install_dir), toml::to_string_pretty(&resolved)?)
+        .context("Failed to cache resolved Python interpreter")
+}
+
+/// Read `requires-python` (e.g. `">=3.12"`) out of `pyproject.toml` and
+/// return a bare version string `uv python install` accepts.
+fn required_version(backend_path: &Path) -> Result<String> {
+    let pyproject_path = backend_path.join("pyproject.toml");
+    let contents = std::fs::read_to_string(&pyproject_path)
+        .with_context(|| format!("Failed to read {:?}", pyproject_path))?;
+    let parsed: toml::Value = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {:?}", pyproject_path))?;
+
+    let requires_python = parsed
+        .get("project")
+        .and_then(|p| p.get("requires-python"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("pyproject.toml has no [project].requires-python"))?;
+
+    // `requires-python` may be a compound specifier (e.g. `">=3.10,<3.13"`);
+    // `uv python install` wants a single version, so take the lower bound
+    // (the first comma-separated clause) and strip its comparison operator.
+    let first_clause = requires_python
+        .split(',')
+        .next()
+        .unwrap_or(requires_python)
+        .trim();
+    let version = first_clause.trim_start_matches(|c: char| !c.is_ascii_digit());
+
+    if version.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Could not parse a version out of requires-python = {:?}",
+            requires_python
+        ));
+    }
+
+    Ok(version.to_string())
+}
+
+/// Fetch (or reuse a cached) standalone CPython pinned to `pyproject.toml`'s
+/// `requires-python`, installed under `data_dir/python`.
+///
+/// Returns the absolute path to the interpreter, suitable for `uv run --python <path>`.
+pub fn provision_managed(data_dir: &Path, backend_path: &Path, uv_cmd: &str) -> Result<String> {
+    let version = required_version(backend_path)?;
+    let install_dir = data_dir.join("python");
+    create_dir_all(&install_dir).context("Failed to create managed Python directory")?;
+
+    if let Some(cached) = load_cached(&install_dir, &version) {
+        log::info!("Using cached managed Python {} at {:?}", version, cached);
+        return Ok(cached);
+    }
+
+    log::info!("Installing managed Python {} into {:?}", version, install_dir);
+    let status = Command::new(uv_cmd)
+        .arg("python")
+        .arg("install")
+        .arg(&version)
+        .arg("--install-dir")
+        .arg(&install_dir)
+        .status()
+        .context("Failed to run uv python install")?;
+
+    if !status.success() {
+        return Err(anyhow::anyhow!("uv python install {} failed", version));
+    }
+
+    let output = Command::new(uv_cmd)
+        .arg("python")
+        .arg("find")
+        .arg(&version)
+        .arg("--install-dir")
+        .arg(&install_dir)
+        .output()
+        .context("Failed to run uv python find")?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("uv python find {} failed", version));
+    }
+
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    store_cache(&install_dir, &version, &path)?;
+
+    log::info!("✓ Managed Python {} ready at {:?}", version, path);
+    Ok(path)
+}
@@ -0,0 +1,254 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tauri::AppHandle;
+
+use crate::agent_backend::Backend;
+use crate::backend::{spawn_agent, spawn_backend_server};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const BACKOFF_BASE_SECS: u64 = 1;
+const BACKOFF_CAP: Duration = Duration::from_secs(60);
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+const MAX_RESTARTS: u32 = 10;
+
+/// Process names the user has durably stopped via `stop_agent`, shared
+/// between `BackendManager` and `Supervisor`.
+pub type DisabledSet = Arc<Mutex<HashSet<String>>>;
+
+/// Monotonic counter handed out to each spawned `ManagedProcess`.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies which spawn path restarts a given tracked process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessKind {
+    Agent(String),
+    Server,
+}
+
+impl fmt::Display for ProcessKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessKind::Agent(name) => write!(f, "{}", name),
+            ProcessKind::Server => write!(f, "backend server"),
+        }
+    }
+}
+
+/// A spawned child process tracked for crash detection and restart.
+pub struct ManagedProcess {
+    pub child: Child,
+    pub kind: ProcessKind,
+    pub restart_count: u32,
+    pub last_start: Instant,
+    /// Distinguishes this spawn from any other one with the same `kind`.
+    pub generation: u64,
+}
+
+impl ManagedProcess {
+    pub fn new(child: Child, kind: ProcessKind) -> Self {
+        Self::with_restart_count(child, kind, 0)
+    }
+
+    /// Construct a process that has already been restarted `restart_count`
+    /// times, the single path every (re)spawn site should go through so
+    /// `generation` is never forgotten.
+    pub fn with_restart_count(child: Child, kind: ProcessKind, restart_count: u32) -> Self {
+        Self {
+            child,
+            kind,
+            restart_count,
+            last_start: Instant::now(),
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+}
+
+/// Watches tracked child processes and restarts crashed ones through the
+/// same spawn path, backing off exponentially (1s, 2s, 4s, ... capped at
+/// 60s) and giving up after `MAX_RESTARTS` attempts.
+pub struct Supervisor {
+    processes: Arc<Mutex<Vec<ManagedProcess>>>,
+    backends: Arc<Vec<Box<dyn Backend>>>,
+    backend_path: PathBuf,
+    env_path: PathBuf,
+    log_dir: PathBuf,
+    uv_cmd: String,
+    python: Option<String>,
+    stopping: Arc<AtomicBool>,
+    disabled: DisabledSet,
+    app_handle: AppHandle,
+}
+
+impl Supervisor {
+    pub fn new(
+        processes: Arc<Mutex<Vec<ManagedProcess>>>,
+        backends: Arc<Vec<Box<dyn Backend>>>,
+        backend_path: PathBuf,
+        env_path: PathBuf,
+        log_dir: PathBuf,
+        uv_cmd: String,
+        python: Option<String>,
+        stopping: Arc<AtomicBool>,
+        disabled: DisabledSet,
+        app_handle: AppHandle,
+    ) -> Self {
+        Self {
+            processes,
+            backends,
+            backend_path,
+            env_path,
+            log_dir,
+            uv_cmd,
+            python,
+            stopping,
+            disabled,
+            app_handle,
+        }
+    }
+
+    /// Poll loop body. Exits as soon as `stopping` is observed, so shutdown
+    /// isn't fought by a restart that's about to happen.
+    pub fn run(&self) {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if self.stopping.load(Ordering::SeqCst) {
+                return;
+            }
+
+            for (kind, generation) in self.poll_once() {
+                if self.stopping.load(Ordering::SeqCst) {
+                    return;
+                }
+                self.restart(kind, generation);
+            }
+        }
+    }
+
+    /// Check every tracked process once, returning the ones that have
+    /// exited unexpectedly (with the generation they exited at) and
+    /// resetting backoff for long-stable survivors.
+    fn poll_once(&self) -> Vec<(ProcessKind, u64)> {
+        let mut processes = self.processes.lock().unwrap();
+        let mut crashed = Vec::new();
+
+        for proc in processes.iter_mut() {
+            match proc.child.try_wait() {
+                Ok(Some(status)) => {
+                    log::warn!("{} exited with status: {:?}", proc.kind, status);
+                    crashed.push((proc.kind.clone(), proc.generation));
+                }
+                Ok(None) => {
+                    if proc.restart_count > 0 && proc.last_start.elapsed() >= STABLE_AFTER {
+                        log::info!("{} has been stable, resetting restart backoff", proc.kind);
+                        proc.restart_count = 0;
+                    }
+                }
+                Err(e) => log::error!("Error polling {}: {}", proc.kind, e),
+            }
+        }
+
+        crashed
+    }
+
+    /// Restart a crashed process through its original spawn path, backing
+    /// off before respawning and giving up past `MAX_RESTARTS`. `generation`
+    /// identifies the exact spawn that crashed, so this is a no-op if a
+    /// manual stop/restart already replaced or removed it in the meantime.
+    fn restart(&self, kind: ProcessKind, generation: u64) {
+        if self.disabled.lock().unwrap().contains(&kind.to_string()) {
+            log::info!("{} was stopped by the user, not restarting", kind);
+            return;
+        }
+
+        let restart_count = {
+            let mut processes = self.processes.lock().unwrap();
+            let Some(pos) = processes
+                .iter()
+                .position(|p| p.kind == kind && p.generation == generation)
+            else {
+                return;
+            };
+            let count = processes[pos].restart_count;
+            processes.remove(pos);
+            count
+        };
+
+        if restart_count >= MAX_RESTARTS {
+            log::error!(
+                "{} crashed and exceeded {} restart attempts, giving up",
+                kind, MAX_RESTARTS
+            );
+            return;
+        }
+
+        let backoff = Duration::from_secs(BACKOFF_BASE_SECS << restart_count.min(6)).min(BACKOFF_CAP);
+        log::warn!(
+            "{} exited unexpectedly, restarting in {:?} (attempt {}/{})",
+            kind, backoff, restart_count + 1, MAX_RESTARTS
+        );
+        thread::sleep(backoff);
+
+        if self.stopping.load(Ordering::SeqCst) {
+            return;
+        }
+
+        if self.disabled.lock().unwrap().contains(&kind.to_string()) {
+            log::info!("{} was stopped by the user during backoff, not restarting", kind);
+            return;
+        }
+
+        let spawned = match &kind {
+            ProcessKind::Agent(name) => self
+                .backends
+                .iter()
+                .find(|b| b.name() == name)
+                .ok_or_else(|| anyhow::anyhow!("Unknown backend: {}", name))
+                .and_then(|b| {
+                    spawn_agent(
+                        &self.app_handle,
+                        b.as_ref(),
+                        &self.backend_path,
+                        &self.env_path,
+                        &self.log_dir,
+                        &self.uv_cmd,
+                        self.python.as_deref(),
+                    )
+                }),
+            ProcessKind::Server => spawn_backend_server(
+                &self.app_handle,
+                &self.backend_path,
+                &self.env_path,
+                &self.log_dir,
+                &self.uv_cmd,
+                self.python.as_deref(),
+            ),
+        };
+
+        match spawned {
+            Ok(child) => {
+                log::info!("✓ {} restarted with PID: {}", kind, child.id());
+                self.processes
+                    .lock()
+                    .unwrap()
+                    .push(ManagedProcess::with_restart_count(child, kind, restart_count + 1));
+            }
+            Err(e) => log::error!("Failed to restart {}: {}", kind, e),
+        }
+    }
+}
+
+/// Spawn the supervisor loop on a background thread. The thread is not
+/// joined by the caller; it observes `stopping` and exits on its own.
+pub fn spawn_thread(supervisor: Arc<Supervisor>) -> JoinHandle<()> {
+    thread::Builder::new()
+        .name("backend-supervisor".to_string())
+        .spawn(move || supervisor.run())
+        .expect("Failed to spawn supervisor thread")
+}